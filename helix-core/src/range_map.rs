@@ -0,0 +1,287 @@
+//! `RangeMap` attaches arbitrary data to ranges of char offsets in the
+//! buffer, so editor features can associate metadata (fold state, per-range
+//! annotations, inline decoration kinds, soft-wrap hints) with spans of text
+//! without needing a separate interval tree.
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use crate::{Assoc, ChangeSet};
+
+/// A single entry in a [`RangeMap`]: the half-open char range
+/// `offset..offset + len.get()` together with its associated `data`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry<T> {
+    offset: usize,
+    len: NonZeroUsize,
+    data: T,
+}
+
+impl<T> Entry<T> {
+    #[inline]
+    fn end(&self) -> usize {
+        self.offset + self.len.get()
+    }
+}
+
+/// Maps char-offset ranges to values of type `T`.
+///
+/// Internally this is a flat `Vec` of entries kept sorted by `offset` and
+/// non-overlapping, rather than a tree. For the read-heavy access patterns
+/// an editor has, this flat-sorted-`Vec` layout is substantially faster and
+/// more cache-friendly than a tree-based interval map.
+///
+/// Writing through [`RangeMap::insert`] splits any entry that only
+/// partially overlaps the written range, then coalesces neighboring entries
+/// whose `data` compares equal and whose ranges are now contiguous. That
+/// coalescing is purely an internal memory optimization: callers must not
+/// observe it (or its absence) through iteration, only which ranges map to
+/// which data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeMap<T> {
+    entries: Vec<Entry<T>>,
+}
+
+impl<T> Default for RangeMap<T> {
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl<T> RangeMap<T> {
+    /// Constructs an empty `RangeMap`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Looks up the value whose range contains `pos`, if any.
+    #[must_use]
+    pub fn get(&self, pos: usize) -> Option<&T> {
+        let index = self.entry_at(pos)?;
+        Some(&self.entries[index].data)
+    }
+
+    /// Finds the index of the entry containing `pos`, via binary search on
+    /// `offset`.
+    fn entry_at(&self, pos: usize) -> Option<usize> {
+        let index = match self.entries.binary_search_by_key(&pos, |entry| entry.offset) {
+            Ok(index) => index,
+            Err(0) => return None,
+            Err(index) => index - 1,
+        };
+
+        let entry = &self.entries[index];
+        (entry.offset <= pos && pos < entry.end()).then_some(index)
+    }
+
+    /// Iterates over every entry that intersects `range`, in offset order.
+    pub fn iter_range(
+        &self,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = (Range<usize>, &T)> + '_ {
+        let start = match self
+            .entries
+            .binary_search_by_key(&range.start, |entry| entry.offset)
+        {
+            Ok(index) => index,
+            Err(0) => 0,
+            // The entry just before `range.start` may still overlap it.
+            Err(index) if self.entries[index - 1].end() > range.start => index - 1,
+            Err(index) => index,
+        };
+
+        self.entries[start..]
+            .iter()
+            .take_while(move |entry| entry.offset < range.end)
+            .map(|entry| (entry.offset..entry.end(), &entry.data))
+    }
+}
+
+impl<T: Clone + PartialEq> RangeMap<T> {
+    /// Inserts `data` for `range`, splitting any existing entry that only
+    /// partially overlaps `range` so the write exactly covers it, then
+    /// coalescing with neighboring entries whose `data` compares equal and
+    /// whose ranges are now contiguous.
+    pub fn insert(&mut self, range: Range<usize>, data: T) {
+        assert!(range.start < range.end, "range must be non-empty");
+
+        self.remove_range(range.clone());
+
+        let index = self
+            .entries
+            .partition_point(|entry| entry.offset < range.start);
+
+        self.entries.insert(
+            index,
+            Entry {
+                offset: range.start,
+                len: NonZeroUsize::new(range.end - range.start).unwrap(),
+                data,
+            },
+        );
+
+        self.coalesce_around(index);
+    }
+
+    /// Clears `range`, shrinking or splitting any entry that only partially
+    /// overlaps it so that afterwards no entry intersects `range` at all.
+    fn remove_range(&mut self, range: Range<usize>) {
+        let start = match self
+            .entries
+            .binary_search_by_key(&range.start, |entry| entry.offset)
+        {
+            Ok(index) => index,
+            Err(0) => 0,
+            Err(index) if self.entries[index - 1].end() > range.start => index - 1,
+            Err(index) => index,
+        };
+
+        let mut end = start;
+        let mut remainders = Vec::new();
+
+        while end < self.entries.len() && self.entries[end].offset < range.end {
+            let entry = &self.entries[end];
+
+            if entry.offset < range.start {
+                remainders.push(Entry {
+                    offset: entry.offset,
+                    len: NonZeroUsize::new(range.start - entry.offset).unwrap(),
+                    data: entry.data.clone(),
+                });
+            }
+            if entry.end() > range.end {
+                remainders.push(Entry {
+                    offset: range.end,
+                    len: NonZeroUsize::new(entry.end() - range.end).unwrap(),
+                    data: entry.data.clone(),
+                });
+            }
+
+            end += 1;
+        }
+
+        self.entries.splice(start..end, remainders);
+    }
+
+    /// Merges the entry at `index` with either neighbor whose range is
+    /// contiguous with it and whose `data` compares equal.
+    fn coalesce_around(&mut self, index: usize) {
+        // Merge with the following neighbor first so `index` doesn't shift.
+        if index + 1 < self.entries.len()
+            && self.entries[index].end() == self.entries[index + 1].offset
+            && self.entries[index].data == self.entries[index + 1].data
+        {
+            let next = self.entries.remove(index + 1);
+            self.entries[index].len =
+                NonZeroUsize::new(self.entries[index].len.get() + next.len.get()).unwrap();
+        }
+
+        if index > 0
+            && self.entries[index - 1].end() == self.entries[index].offset
+            && self.entries[index - 1].data == self.entries[index].data
+        {
+            let current = self.entries.remove(index);
+            self.entries[index - 1].len =
+                NonZeroUsize::new(self.entries[index - 1].len.get() + current.len.get()).unwrap();
+        }
+    }
+}
+
+impl<T> RangeMap<T> {
+    /// Maps every entry through a set of changes, mirroring `Range::map`.
+    /// Entries fully covered by a deletion are dropped.
+    #[must_use]
+    pub fn map(self, changes: &ChangeSet) -> Self {
+        if changes.is_empty() {
+            return self;
+        }
+
+        let entries = self
+            .entries
+            .into_iter()
+            .filter_map(|entry| {
+                let start = changes.map_pos(entry.offset, Assoc::After);
+                let end = changes.map_pos(entry.end(), Assoc::After);
+
+                NonZeroUsize::new(end.saturating_sub(start)).map(|len| Entry {
+                    offset: start,
+                    len,
+                    data: entry.data,
+                })
+            })
+            .collect();
+
+        Self { entries }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn entries(map: &RangeMap<&'static str>) -> Vec<(Range<usize>, &'static str)> {
+        map.iter_range(0..usize::MAX)
+            .map(|(range, data)| (range, *data))
+            .collect()
+    }
+
+    #[test]
+    fn test_get() {
+        let mut map = RangeMap::new();
+        map.insert(2..5, "a");
+        map.insert(8..10, "b");
+
+        assert_eq!(map.get(0), None);
+        assert_eq!(map.get(2), Some(&"a"));
+        assert_eq!(map.get(4), Some(&"a"));
+        assert_eq!(map.get(5), None);
+        assert_eq!(map.get(8), Some(&"b"));
+        assert_eq!(map.get(9), Some(&"b"));
+        assert_eq!(map.get(10), None);
+    }
+
+    #[test]
+    fn test_insert_splits_overlapping_entry() {
+        let mut map = RangeMap::new();
+        map.insert(0..10, "a");
+        // Writing through the middle splits the entry into two remainders.
+        map.insert(4..6, "b");
+
+        assert_eq!(entries(&map), vec![(0..4, "a"), (4..6, "b"), (6..10, "a")]);
+
+        // Writing that only overlaps one edge shrinks that entry.
+        map.insert(8..12, "c");
+        assert_eq!(
+            entries(&map),
+            vec![(0..4, "a"), (4..6, "b"), (6..8, "a"), (8..12, "c")]
+        );
+    }
+
+    #[test]
+    fn test_insert_coalesces_equal_contiguous_neighbors() {
+        let mut map = RangeMap::new();
+        map.insert(0..3, "a");
+        map.insert(3..6, "a");
+        map.insert(6..9, "b");
+
+        // The two "a" entries merge into one; "b" stays separate.
+        assert_eq!(entries(&map), vec![(0..6, "a"), (6..9, "b")]);
+    }
+
+    #[test]
+    fn test_iter_range() {
+        let mut map = RangeMap::new();
+        map.insert(0..3, "a");
+        map.insert(5..8, "b");
+        map.insert(10..12, "c");
+
+        let result: Vec<_> = map
+            .iter_range(2..11)
+            .map(|(range, data)| (range, *data))
+            .collect();
+
+        assert_eq!(result, vec![(0..3, "a"), (5..8, "b"), (10..12, "c")]);
+    }
+}
@@ -313,6 +313,244 @@ impl Selection {
     pub fn len(&self) -> usize {
         self.ranges.len()
     }
+
+    /// Finds the index of the range containing `pos`, if any, via binary
+    /// search. Relies on `normalize`'s invariant that ranges are sorted by
+    /// `from()` and non-overlapping.
+    #[must_use]
+    pub fn range_at(&self, pos: usize) -> Option<usize> {
+        debug_assert!(self.ranges.windows(2).all(|w| w[0].from() <= w[1].from()));
+
+        // Partitions on `from()`: the last range whose `from() <= pos` is
+        // the only one that could possibly contain `pos`.
+        let index = self.ranges.partition_point(|range| range.from() <= pos);
+        if index == 0 {
+            return None;
+        }
+
+        (pos < self.ranges[index - 1].to()).then_some(index - 1)
+    }
+
+    /// `true` if any range in the selection contains `pos`.
+    #[must_use]
+    pub fn contains(&self, pos: usize) -> bool {
+        self.range_at(pos).is_some()
+    }
+
+    /// `true` if any range in the selection overlaps `range`, using the same
+    /// left-inclusive, right-exclusive semantics as `Range::contains`.
+    #[must_use]
+    pub fn intersects(&self, range: std::ops::Range<usize>) -> bool {
+        debug_assert!(self.ranges.windows(2).all(|w| w[0].from() <= w[1].from()));
+
+        // Partitions on `to()`: the first range whose `to() > range.start`
+        // is the only one that could possibly overlap `range`.
+        let index = self.ranges.partition_point(|r| r.to() <= range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.from() < range.end)
+    }
+
+    /// Constructs a selection from a `Vec` of ranges that the caller guarantees
+    /// are already sorted by `from()` and non-overlapping, skipping the
+    /// `normalize` pass. `primary_index` must be a valid index into `ranges`.
+    fn from_sorted_ranges(ranges: SmallVec<[Range; 1]>, primary_index: usize) -> Option<Self> {
+        if ranges.is_empty() {
+            return None;
+        }
+
+        debug_assert!(
+            ranges.windows(2).all(|w| w[0].from() <= w[1].from() && !w[0].overlaps(&w[1])),
+            "ranges must be sorted and non-overlapping"
+        );
+
+        Some(Self {
+            ranges,
+            primary_index,
+        })
+    }
+
+    /// Picks the index of whichever range in `ranges` still contains
+    /// `old_primary_from`, falling back to `0` if none does.
+    fn pick_primary_index(ranges: &[Range], old_primary_from: usize) -> usize {
+        ranges
+            .iter()
+            .position(|range| range.contains(old_primary_from))
+            .unwrap_or(0)
+    }
+
+    /// Computes the union of `self` and `other`: every position covered by
+    /// either selection. Overlapping and directly-adjacent ranges (including
+    /// zero-width ranges sitting on a shared edge) are coalesced into one.
+    ///
+    /// Output ranges are oriented `from()` -> `to()` (anchor == from, head ==
+    /// to), since a merged range has no single meaningful direction to
+    /// inherit from its inputs. The primary index follows `self`'s primary
+    /// range into whichever output range still contains its `from()`,
+    /// falling back to `0`.
+    #[must_use]
+    pub fn union(&self, other: &Selection) -> Option<Selection> {
+        let ranges = union_ranges(self.ranges(), other.ranges());
+        let primary_index = Self::pick_primary_index(&ranges, self.primary().from());
+        Self::from_sorted_ranges(ranges, primary_index)
+    }
+
+    /// Computes the intersection of `self` and `other`: every position
+    /// covered by both selections. Returns `None` if the two selections
+    /// don't overlap at all.
+    ///
+    /// Output ranges are oriented `from()` -> `to()`. The primary index
+    /// follows `self`'s primary range into whichever output range still
+    /// contains its `from()`, falling back to `0`.
+    #[must_use]
+    pub fn intersection(&self, other: &Selection) -> Option<Selection> {
+        let ranges = intersection_ranges(self.ranges(), other.ranges());
+        let primary_index = Self::pick_primary_index(&ranges, self.primary().from());
+        Self::from_sorted_ranges(ranges, primary_index)
+    }
+
+    /// Computes `self \ other`: every position covered by `self` but not by
+    /// `other`. Returns `None` if `other` fully covers `self`.
+    ///
+    /// Output ranges are oriented `from()` -> `to()`. The primary index
+    /// follows `self`'s primary range into whichever output range still
+    /// contains its `from()`, falling back to `0`.
+    #[must_use]
+    pub fn difference(&self, other: &Selection) -> Option<Selection> {
+        let ranges = difference_ranges(self.ranges(), other.ranges());
+        let primary_index = Self::pick_primary_index(&ranges, self.primary().from());
+        Self::from_sorted_ranges(ranges, primary_index)
+    }
+
+    /// Computes the symmetric difference of `self` and `other`:
+    /// `(self \ other) ∪ (other \ self)`, the positions covered by exactly
+    /// one of the two selections.
+    ///
+    /// Output ranges are oriented `from()` -> `to()`. The primary index
+    /// follows `self`'s primary range into whichever output range still
+    /// contains its `from()`, falling back to `0`.
+    #[must_use]
+    pub fn symmetric_difference(&self, other: &Selection) -> Option<Selection> {
+        let self_minus_other = difference_ranges(self.ranges(), other.ranges());
+        let other_minus_self = difference_ranges(other.ranges(), self.ranges());
+        let ranges = union_ranges(&self_minus_other, &other_minus_self);
+        let primary_index = Self::pick_primary_index(&ranges, self.primary().from());
+        Self::from_sorted_ranges(ranges, primary_index)
+    }
+}
+
+/// Merges two sorted-by-`from()`, non-overlapping range slices into one
+/// sorted `SmallVec`, preserving duplicates. A single linear two-pointer
+/// merge, the same step a merge sort uses to combine two sorted runs.
+fn merge_sorted_ranges(a: &[Range], b: &[Range]) -> SmallVec<[Range; 1]> {
+    let mut result = SmallVec::with_capacity(a.len() + b.len());
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < a.len() && j < b.len() {
+        if a[i].from() <= b[j].from() {
+            result.push(a[i]);
+            i += 1;
+        } else {
+            result.push(b[j]);
+            j += 1;
+        }
+    }
+
+    result.extend_from_slice(&a[i..]);
+    result.extend_from_slice(&b[j..]);
+    result
+}
+
+/// Union of two sorted, non-overlapping range slices: a linear merge
+/// followed by a coalescing pass that joins overlapping or directly
+/// adjacent ranges (so zero-width ranges on a shared edge merge in too).
+fn union_ranges(a: &[Range], b: &[Range]) -> SmallVec<[Range; 1]> {
+    let merged = merge_sorted_ranges(a, b);
+
+    let mut result: SmallVec<[Range; 1]> = SmallVec::with_capacity(merged.len());
+    for range in merged {
+        match result.last_mut() {
+            // `<=`, not `<`, so that directly-adjacent ranges (and
+            // zero-width ranges sitting on the edge) coalesce too.
+            Some(prev) if range.from() <= prev.to() => {
+                prev.head = prev.to().max(range.to());
+                prev.anchor = prev.from();
+            }
+            _ => result.push(Range::new(range.from(), range.to())),
+        }
+    }
+    result
+}
+
+/// Intersection of two sorted, non-overlapping range slices: a linear
+/// two-pointer walk emitting `max(a.from, b.from)..min(a.to, b.to)` for
+/// every overlapping pair, advancing whichever range ends first.
+fn intersection_ranges(a: &[Range], b: &[Range]) -> SmallVec<[Range; 1]> {
+    let mut result = SmallVec::new();
+    let mut i = 0;
+    let mut j = 0;
+
+    while i < a.len() && j < b.len() {
+        let from = a[i].from().max(b[j].from());
+        let to = a[i].to().min(b[j].to());
+
+        if from < to {
+            result.push(Range::new(from, to));
+        }
+
+        if a[i].to() < b[j].to() {
+            i += 1;
+        } else if b[j].to() < a[i].to() {
+            j += 1;
+        } else {
+            i += 1;
+            j += 1;
+        }
+    }
+
+    result
+}
+
+/// `a \ b` for two sorted, non-overlapping range slices: for each range in
+/// `a`, subtract every overlapping range in `b` from a running "remaining
+/// start" cursor, emitting the gaps between them. A range in `a` fully
+/// covered by `b` emits nothing.
+fn difference_ranges(a: &[Range], b: &[Range]) -> SmallVec<[Range; 1]> {
+    let mut result = SmallVec::new();
+    let mut j = 0;
+
+    for a_range in a {
+        let a_to = a_range.to();
+        let mut cursor = a_range.from();
+
+        // Skip `b` ranges that end before this `a` range even starts.
+        while j < b.len() && b[j].to() <= cursor {
+            j += 1;
+        }
+
+        let mut k = j;
+        while k < b.len() && b[k].from() < a_to {
+            if b[k].from() > cursor {
+                result.push(Range::new(cursor, b[k].from()));
+            }
+            cursor = cursor.max(b[k].to());
+
+            // This `b` range extends past the current `a` range, so it may
+            // still overlap the next one: leave it for the next iteration.
+            if b[k].to() >= a_to {
+                break;
+            }
+            k += 1;
+        }
+        j = k;
+
+        if cursor < a_to {
+            result.push(Range::new(cursor, a_to));
+        }
+    }
+
+    result
 }
 
 impl<'a> IntoIterator for &'a Selection {
@@ -590,4 +828,138 @@ mod test {
             &["", "abcd", "efg", "rs", "xyz"]
         );
     }
+
+    fn ranges_str(sel: &Selection) -> String {
+        sel.ranges()
+            .iter()
+            .map(|range| format!("{}/{}", range.anchor, range.head))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    #[test]
+    fn test_union() {
+        // Overlapping and directly adjacent ranges merge; disjoint ranges stay separate.
+        let a = Selection::new(smallvec![Range::new(0, 3), Range::new(6, 8)], 0);
+        let b = Selection::new(smallvec![Range::new(2, 4), Range::new(8, 10)], 0);
+
+        let result = a.union(&b).unwrap();
+        assert_eq!(ranges_str(&result), "0/4,6/10");
+
+        // Disjoint, non-adjacent ranges are kept apart.
+        let a = Selection::new(smallvec![Range::new(0, 2)], 0);
+        let b = Selection::new(smallvec![Range::new(4, 6)], 0);
+
+        let result = a.union(&b).unwrap();
+        assert_eq!(ranges_str(&result), "0/2,4/6");
+
+        // A zero-width range sitting on a shared edge still coalesces.
+        let a = Selection::new(smallvec![Range::new(0, 3)], 0);
+        let b = Selection::point(3);
+
+        let result = a.union(&b).unwrap();
+        assert_eq!(ranges_str(&result), "0/3");
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = Selection::new(smallvec![Range::new(0, 5), Range::new(8, 12)], 0);
+        let b = Selection::new(smallvec![Range::new(3, 9), Range::new(10, 14)], 0);
+
+        let result = a.intersection(&b).unwrap();
+        assert_eq!(ranges_str(&result), "3/5,8/9,10/12");
+
+        // No overlap at all.
+        let a = Selection::new(smallvec![Range::new(0, 2)], 0);
+        let b = Selection::new(smallvec![Range::new(4, 6)], 0);
+        assert_eq!(a.intersection(&b), None);
+
+        // Merely touching edges do not intersect (right-exclusive).
+        let a = Selection::new(smallvec![Range::new(0, 3)], 0);
+        let b = Selection::new(smallvec![Range::new(3, 6)], 0);
+        assert_eq!(a.intersection(&b), None);
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = Selection::new(smallvec![Range::new(0, 10)], 0);
+        let b = Selection::new(smallvec![Range::new(3, 5), Range::new(7, 8)], 0);
+
+        let result = a.difference(&b).unwrap();
+        assert_eq!(ranges_str(&result), "0/3,5/7,8/10");
+
+        // Fully covered range emits nothing.
+        let a = Selection::new(smallvec![Range::new(2, 4)], 0);
+        let b = Selection::new(smallvec![Range::new(0, 10)], 0);
+        assert_eq!(a.difference(&b), None);
+
+        // No overlap leaves `a` untouched.
+        let a = Selection::new(smallvec![Range::new(0, 2)], 0);
+        let b = Selection::new(smallvec![Range::new(4, 6)], 0);
+        let result = a.difference(&b).unwrap();
+        assert_eq!(ranges_str(&result), "0/2");
+    }
+
+    #[test]
+    fn test_symmetric_difference() {
+        let a = Selection::new(smallvec![Range::new(0, 5), Range::new(8, 12)], 0);
+        let b = Selection::new(smallvec![Range::new(3, 9), Range::new(10, 14)], 0);
+
+        let result = a.symmetric_difference(&b).unwrap();
+        assert_eq!(ranges_str(&result), "0/3,5/8,9/10,12/14");
+
+        // Identical selections fully cancel out.
+        let a = Selection::new(smallvec![Range::new(0, 5)], 0);
+        assert_eq!(a.symmetric_difference(&a), None);
+    }
+
+    #[test]
+    fn test_range_at() {
+        let sel = Selection::new(
+            smallvec![Range::new(0, 3), Range::new(5, 5), Range::new(8, 10)],
+            0,
+        );
+
+        // Left-inclusive: the start of a range belongs to it.
+        assert_eq!(sel.range_at(0), Some(0));
+        assert_eq!(sel.range_at(2), Some(0));
+        // Right-exclusive: the end of a range does not belong to it.
+        assert_eq!(sel.range_at(3), None);
+        // Between ranges.
+        assert_eq!(sel.range_at(4), None);
+        // A zero-width range, like a zero-width `Range`, never contains its
+        // own position (right-exclusive).
+        assert_eq!(sel.range_at(5), None);
+        assert_eq!(sel.range_at(6), None);
+        assert_eq!(sel.range_at(8), Some(2));
+        assert_eq!(sel.range_at(9), Some(2));
+        assert_eq!(sel.range_at(10), None);
+    }
+
+    #[test]
+    fn test_selection_contains() {
+        let sel = Selection::new(smallvec![Range::new(0, 3), Range::new(8, 10)], 0);
+
+        assert!(sel.contains(0));
+        assert!(sel.contains(2));
+        assert!(!sel.contains(3));
+        assert!(!sel.contains(5));
+        assert!(sel.contains(8));
+        assert!(!sel.contains(10));
+    }
+
+    #[test]
+    fn test_intersects() {
+        let sel = Selection::new(smallvec![Range::new(0, 3), Range::new(8, 10)], 0);
+
+        assert!(sel.intersects(0..1));
+        assert!(sel.intersects(2..5));
+        // Merely touching the edge doesn't count (right-exclusive).
+        assert!(!sel.intersects(3..8));
+        assert!(!sel.intersects(4..5));
+        assert!(sel.intersects(9..12));
+        // A zero-width query range still matches if it sits inside a range.
+        assert!(sel.intersects(1..1));
+        assert!(!sel.intersects(3..3));
+    }
 }